@@ -1,10 +1,15 @@
 //! Miden RPC client with native miden_objects types
 use miden_objects::{
-    account::AccountId,
-    note::{NoteId, NoteTag},
-    utils::Serializable,
-    Word,
+    account::{Account, AccountId},
+    block::Block,
+    crypto::merkle::{MerklePath, MmrPeaks, MmrProof as NativeMmrProof},
+    note::{Note, NoteId, NoteTag},
+    utils::{Deserializable, Serializable},
+    Felt, Word,
 };
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use thiserror::Error;
 use tonic::{Request, transport::{Channel, ClientTlsConfig}};
 
 // Re-export proto types for advanced usage
@@ -13,6 +18,800 @@ pub use miden_node_proto::generated::{
 };
 pub use rpc::api_client::ApiClient;
 
+/// Errors returned by MidenRpcClient
+/// Preserves the tonic status code so callers can distinguish e.g. NotFound from Unavailable
+#[derive(Debug, Error)]
+pub enum MidenRpcError {
+    /// Failed to establish a connection to the node.
+    #[error("failed to connect to {endpoint}: {source}")]
+    Connect {
+        endpoint: String,
+        #[source]
+        source: tonic::transport::Error,
+    },
+
+    /// TLS configuration for the connection was invalid.
+    #[error("TLS config error: {0}")]
+    Tls(tonic::transport::Error),
+
+    /// A lower-level transport error, such as an invalid endpoint passed to connect.
+    #[error("transport error: {0}")]
+    Transport(#[from] tonic::transport::Error),
+
+    /// The node returned a gRPC error status for the call.
+    #[error("RPC call failed with status {code:?}: {message}")]
+    Status { code: tonic::Code, message: String },
+
+    /// The response was missing a field the client expected to be present.
+    #[error("missing field in response: {0}")]
+    MissingField(&'static str),
+
+    /// Failed to decode a value from the wire format.
+    #[error("failed to decode response: {0}")]
+    Decode(String),
+
+    /// An MMR opening the node returned did not authenticate against the claimed chain tip.
+    #[error("block header failed MMR proof verification against the chain tip")]
+    ProofVerification,
+}
+
+impl From<tonic::Status> for MidenRpcError {
+    fn from(status: tonic::Status) -> Self {
+        MidenRpcError::Status {
+            code: status.code(),
+            message: status.message().to_string(),
+        }
+    }
+}
+
+/// The set of gRPC calls MidenRpcClient performs against a Miden node
+/// Implemented for ApiClient<Channel> and for mock::MockTransport
+pub trait RpcTransport {
+    async fn status(
+        &mut self,
+        request: Request<()>,
+    ) -> Result<tonic::Response<rpc::RpcStatus>, tonic::Status>;
+
+    async fn get_block_header_by_number(
+        &mut self,
+        request: Request<shared::BlockHeaderByNumberRequest>,
+    ) -> Result<tonic::Response<shared::BlockHeaderByNumberResponse>, tonic::Status>;
+
+    async fn submit_proven_transaction(
+        &mut self,
+        request: Request<transaction::ProvenTransaction>,
+    ) -> Result<tonic::Response<block_producer::SubmitProvenTransactionResponse>, tonic::Status>;
+
+    async fn sync_state(
+        &mut self,
+        request: Request<rpc_store::SyncStateRequest>,
+    ) -> Result<tonic::Response<rpc_store::SyncStateResponse>, tonic::Status>;
+
+    async fn check_nullifiers(
+        &mut self,
+        request: Request<rpc_store::NullifierList>,
+    ) -> Result<tonic::Response<rpc_store::CheckNullifiersResponse>, tonic::Status>;
+
+    async fn get_notes_by_id(
+        &mut self,
+        request: Request<note::NoteIdList>,
+    ) -> Result<tonic::Response<note::CommittedNoteList>, tonic::Status>;
+
+    async fn get_account_details(
+        &mut self,
+        request: Request<account::AccountId>,
+    ) -> Result<tonic::Response<account::AccountDetails>, tonic::Status>;
+
+    async fn get_account_proofs(
+        &mut self,
+        request: Request<rpc_store::AccountProofsRequest>,
+    ) -> Result<tonic::Response<rpc_store::AccountProofs>, tonic::Status>;
+
+    async fn get_block_by_number(
+        &mut self,
+        request: Request<blockchain::BlockNumber>,
+    ) -> Result<tonic::Response<blockchain::MaybeBlock>, tonic::Status>;
+
+    async fn submit_proven_batch(
+        &mut self,
+        request: Request<transaction::ProvenTransactionBatch>,
+    ) -> Result<tonic::Response<block_producer::SubmitProvenBatchResponse>, tonic::Status>;
+
+    async fn check_nullifiers_by_prefix(
+        &mut self,
+        request: Request<rpc_store::CheckNullifiersByPrefixRequest>,
+    ) -> Result<tonic::Response<rpc_store::CheckNullifiersByPrefixResponse>, tonic::Status>;
+
+    async fn sync_account_vault(
+        &mut self,
+        request: Request<rpc_store::SyncAccountVaultRequest>,
+    ) -> Result<tonic::Response<rpc_store::SyncAccountVaultResponse>, tonic::Status>;
+
+    async fn sync_notes(
+        &mut self,
+        request: Request<rpc_store::SyncNotesRequest>,
+    ) -> Result<tonic::Response<rpc_store::SyncNotesResponse>, tonic::Status>;
+
+    async fn sync_storage_maps(
+        &mut self,
+        request: Request<rpc_store::SyncStorageMapsRequest>,
+    ) -> Result<tonic::Response<rpc_store::SyncStorageMapsResponse>, tonic::Status>;
+}
+
+impl RpcTransport for ApiClient<Channel> {
+    async fn status(
+        &mut self,
+        request: Request<()>,
+    ) -> Result<tonic::Response<rpc::RpcStatus>, tonic::Status> {
+        self.status(request).await
+    }
+
+    async fn get_block_header_by_number(
+        &mut self,
+        request: Request<shared::BlockHeaderByNumberRequest>,
+    ) -> Result<tonic::Response<shared::BlockHeaderByNumberResponse>, tonic::Status> {
+        self.get_block_header_by_number(request).await
+    }
+
+    async fn submit_proven_transaction(
+        &mut self,
+        request: Request<transaction::ProvenTransaction>,
+    ) -> Result<tonic::Response<block_producer::SubmitProvenTransactionResponse>, tonic::Status>
+    {
+        self.submit_proven_transaction(request).await
+    }
+
+    async fn sync_state(
+        &mut self,
+        request: Request<rpc_store::SyncStateRequest>,
+    ) -> Result<tonic::Response<rpc_store::SyncStateResponse>, tonic::Status> {
+        self.sync_state(request).await
+    }
+
+    async fn check_nullifiers(
+        &mut self,
+        request: Request<rpc_store::NullifierList>,
+    ) -> Result<tonic::Response<rpc_store::CheckNullifiersResponse>, tonic::Status> {
+        self.check_nullifiers(request).await
+    }
+
+    async fn get_notes_by_id(
+        &mut self,
+        request: Request<note::NoteIdList>,
+    ) -> Result<tonic::Response<note::CommittedNoteList>, tonic::Status> {
+        self.get_notes_by_id(request).await
+    }
+
+    async fn get_account_details(
+        &mut self,
+        request: Request<account::AccountId>,
+    ) -> Result<tonic::Response<account::AccountDetails>, tonic::Status> {
+        self.get_account_details(request).await
+    }
+
+    async fn get_account_proofs(
+        &mut self,
+        request: Request<rpc_store::AccountProofsRequest>,
+    ) -> Result<tonic::Response<rpc_store::AccountProofs>, tonic::Status> {
+        self.get_account_proofs(request).await
+    }
+
+    async fn get_block_by_number(
+        &mut self,
+        request: Request<blockchain::BlockNumber>,
+    ) -> Result<tonic::Response<blockchain::MaybeBlock>, tonic::Status> {
+        self.get_block_by_number(request).await
+    }
+
+    async fn submit_proven_batch(
+        &mut self,
+        request: Request<transaction::ProvenTransactionBatch>,
+    ) -> Result<tonic::Response<block_producer::SubmitProvenBatchResponse>, tonic::Status> {
+        self.submit_proven_batch(request).await
+    }
+
+    async fn check_nullifiers_by_prefix(
+        &mut self,
+        request: Request<rpc_store::CheckNullifiersByPrefixRequest>,
+    ) -> Result<tonic::Response<rpc_store::CheckNullifiersByPrefixResponse>, tonic::Status> {
+        self.check_nullifiers_by_prefix(request).await
+    }
+
+    async fn sync_account_vault(
+        &mut self,
+        request: Request<rpc_store::SyncAccountVaultRequest>,
+    ) -> Result<tonic::Response<rpc_store::SyncAccountVaultResponse>, tonic::Status> {
+        self.sync_account_vault(request).await
+    }
+
+    async fn sync_notes(
+        &mut self,
+        request: Request<rpc_store::SyncNotesRequest>,
+    ) -> Result<tonic::Response<rpc_store::SyncNotesResponse>, tonic::Status> {
+        self.sync_notes(request).await
+    }
+
+    async fn sync_storage_maps(
+        &mut self,
+        request: Request<rpc_store::SyncStorageMapsRequest>,
+    ) -> Result<tonic::Response<rpc_store::SyncStorageMapsResponse>, tonic::Status> {
+        self.sync_storage_maps(request).await
+    }
+}
+
+/// An in-memory RpcTransport for unit-testing MidenRpcClient without a live node
+pub mod mock {
+    use super::*;
+    use std::collections::{HashMap, VecDeque};
+
+    /// A single RPC call observed by MockTransport, recorded in the order received
+    #[derive(Debug, Clone)]
+    pub enum RecordedRequest {
+        Status,
+        GetAccountDetails(account::AccountId),
+        SyncState(rpc_store::SyncStateRequest),
+        CheckNullifiers(rpc_store::NullifierList),
+        Other(&'static str),
+    }
+
+    /// Canned responses for RpcTransport, keyed by request where that's meaningful
+    /// Register responses with the with_*/push_* builders, then drive a client through them
+    #[derive(Debug, Default)]
+    pub struct MockTransport {
+        pub requests: Vec<RecordedRequest>,
+        account_details: HashMap<AccountId, account::AccountDetails>,
+        status_responses: VecDeque<rpc::RpcStatus>,
+        sync_state_responses: VecDeque<rpc_store::SyncStateResponse>,
+        check_nullifiers_responses: VecDeque<rpc_store::CheckNullifiersResponse>,
+        submit_transaction_responses: VecDeque<block_producer::SubmitProvenTransactionResponse>,
+    }
+
+    impl MockTransport {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        pub fn with_account_details(
+            mut self,
+            account_id: AccountId,
+            details: account::AccountDetails,
+        ) -> Self {
+            self.account_details.insert(account_id, details);
+            self
+        }
+
+        pub fn push_status_response(mut self, response: rpc::RpcStatus) -> Self {
+            self.status_responses.push_back(response);
+            self
+        }
+
+        pub fn push_sync_state_response(mut self, response: rpc_store::SyncStateResponse) -> Self {
+            self.sync_state_responses.push_back(response);
+            self
+        }
+
+        pub fn push_check_nullifiers_response(
+            mut self,
+            response: rpc_store::CheckNullifiersResponse,
+        ) -> Self {
+            self.check_nullifiers_responses.push_back(response);
+            self
+        }
+
+        pub fn push_submit_transaction_response(
+            mut self,
+            response: block_producer::SubmitProvenTransactionResponse,
+        ) -> Self {
+            self.submit_transaction_responses.push_back(response);
+            self
+        }
+    }
+
+    impl RpcTransport for MockTransport {
+        async fn status(
+            &mut self,
+            _request: Request<()>,
+        ) -> Result<tonic::Response<rpc::RpcStatus>, tonic::Status> {
+            self.requests.push(RecordedRequest::Status);
+            self.status_responses
+                .pop_front()
+                .map(tonic::Response::new)
+                .ok_or_else(|| tonic::Status::unimplemented("no canned status response registered"))
+        }
+
+        async fn get_account_details(
+            &mut self,
+            request: Request<account::AccountId>,
+        ) -> Result<tonic::Response<account::AccountDetails>, tonic::Status> {
+            let proto_id = request.into_inner();
+            self.requests
+                .push(RecordedRequest::GetAccountDetails(proto_id.clone()));
+
+            let account_id = AccountId::read_from_bytes(&proto_id.id)
+                .map_err(|e| tonic::Status::invalid_argument(e.to_string()))?;
+
+            self.account_details
+                .get(&account_id)
+                .cloned()
+                .map(tonic::Response::new)
+                .ok_or_else(|| tonic::Status::not_found("no canned account details registered"))
+        }
+
+        async fn sync_state(
+            &mut self,
+            request: Request<rpc_store::SyncStateRequest>,
+        ) -> Result<tonic::Response<rpc_store::SyncStateResponse>, tonic::Status> {
+            self.requests
+                .push(RecordedRequest::SyncState(request.into_inner()));
+            self.sync_state_responses
+                .pop_front()
+                .map(tonic::Response::new)
+                .ok_or_else(|| {
+                    tonic::Status::unimplemented("no canned sync_state response registered")
+                })
+        }
+
+        async fn check_nullifiers(
+            &mut self,
+            request: Request<rpc_store::NullifierList>,
+        ) -> Result<tonic::Response<rpc_store::CheckNullifiersResponse>, tonic::Status> {
+            self.requests
+                .push(RecordedRequest::CheckNullifiers(request.into_inner()));
+            self.check_nullifiers_responses
+                .pop_front()
+                .map(tonic::Response::new)
+                .ok_or_else(|| {
+                    tonic::Status::unimplemented("no canned check_nullifiers response registered")
+                })
+        }
+
+        async fn get_block_header_by_number(
+            &mut self,
+            _request: Request<shared::BlockHeaderByNumberRequest>,
+        ) -> Result<tonic::Response<shared::BlockHeaderByNumberResponse>, tonic::Status> {
+            self.requests
+                .push(RecordedRequest::Other("get_block_header_by_number"));
+            Err(tonic::Status::unimplemented(
+                "get_block_header_by_number has no canned response",
+            ))
+        }
+
+        async fn submit_proven_transaction(
+            &mut self,
+            _request: Request<transaction::ProvenTransaction>,
+        ) -> Result<tonic::Response<block_producer::SubmitProvenTransactionResponse>, tonic::Status>
+        {
+            self.requests
+                .push(RecordedRequest::Other("submit_proven_transaction"));
+            self.submit_transaction_responses
+                .pop_front()
+                .map(tonic::Response::new)
+                .ok_or_else(|| {
+                    tonic::Status::unimplemented("submit_proven_transaction has no canned response")
+                })
+        }
+
+        async fn get_notes_by_id(
+            &mut self,
+            _request: Request<note::NoteIdList>,
+        ) -> Result<tonic::Response<note::CommittedNoteList>, tonic::Status> {
+            self.requests.push(RecordedRequest::Other("get_notes_by_id"));
+            Err(tonic::Status::unimplemented(
+                "get_notes_by_id has no canned response",
+            ))
+        }
+
+        async fn get_account_proofs(
+            &mut self,
+            _request: Request<rpc_store::AccountProofsRequest>,
+        ) -> Result<tonic::Response<rpc_store::AccountProofs>, tonic::Status> {
+            self.requests
+                .push(RecordedRequest::Other("get_account_proofs"));
+            Err(tonic::Status::unimplemented(
+                "get_account_proofs has no canned response",
+            ))
+        }
+
+        async fn get_block_by_number(
+            &mut self,
+            _request: Request<blockchain::BlockNumber>,
+        ) -> Result<tonic::Response<blockchain::MaybeBlock>, tonic::Status> {
+            self.requests
+                .push(RecordedRequest::Other("get_block_by_number"));
+            Err(tonic::Status::unimplemented(
+                "get_block_by_number has no canned response",
+            ))
+        }
+
+        async fn submit_proven_batch(
+            &mut self,
+            _request: Request<transaction::ProvenTransactionBatch>,
+        ) -> Result<tonic::Response<block_producer::SubmitProvenBatchResponse>, tonic::Status>
+        {
+            self.requests
+                .push(RecordedRequest::Other("submit_proven_batch"));
+            Err(tonic::Status::unimplemented(
+                "submit_proven_batch has no canned response",
+            ))
+        }
+
+        async fn check_nullifiers_by_prefix(
+            &mut self,
+            _request: Request<rpc_store::CheckNullifiersByPrefixRequest>,
+        ) -> Result<tonic::Response<rpc_store::CheckNullifiersByPrefixResponse>, tonic::Status>
+        {
+            self.requests
+                .push(RecordedRequest::Other("check_nullifiers_by_prefix"));
+            Err(tonic::Status::unimplemented(
+                "check_nullifiers_by_prefix has no canned response",
+            ))
+        }
+
+        async fn sync_account_vault(
+            &mut self,
+            _request: Request<rpc_store::SyncAccountVaultRequest>,
+        ) -> Result<tonic::Response<rpc_store::SyncAccountVaultResponse>, tonic::Status> {
+            self.requests
+                .push(RecordedRequest::Other("sync_account_vault"));
+            Err(tonic::Status::unimplemented(
+                "sync_account_vault has no canned response",
+            ))
+        }
+
+        async fn sync_notes(
+            &mut self,
+            _request: Request<rpc_store::SyncNotesRequest>,
+        ) -> Result<tonic::Response<rpc_store::SyncNotesResponse>, tonic::Status> {
+            self.requests.push(RecordedRequest::Other("sync_notes"));
+            Err(tonic::Status::unimplemented(
+                "sync_notes has no canned response",
+            ))
+        }
+
+        async fn sync_storage_maps(
+            &mut self,
+            _request: Request<rpc_store::SyncStorageMapsRequest>,
+        ) -> Result<tonic::Response<rpc_store::SyncStorageMapsResponse>, tonic::Status> {
+            self.requests
+                .push(RecordedRequest::Other("sync_storage_maps"));
+            Err(tonic::Status::unimplemented(
+                "sync_storage_maps has no canned response",
+            ))
+        }
+    }
+}
+
+/// Client-side verification of MMR proofs returned by get_block_header, and a local cache of
+/// headers authenticated against the node's claimed chain tip
+pub mod header_chain {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    /// A block header that has been authenticated against the chain tip
+    #[derive(Debug, Clone)]
+    pub struct VerifiedHeader {
+        pub block_num: u32,
+        pub commitment: Word,
+        pub header: shared::BlockHeader,
+    }
+
+    /// The chain tip a HeaderChain was most recently told to verify against
+    #[derive(Debug, Clone, Copy)]
+    pub struct BestBlock {
+        pub block_num: u32,
+        pub chain_commitment: Word,
+    }
+
+    /// An append-only set of headers authenticated against successive MMR openings
+    #[derive(Debug, Default)]
+    pub struct HeaderChain {
+        headers: BTreeMap<u32, VerifiedHeader>,
+        best_block: Option<BestBlock>,
+        /// Peak snapshots stored by record_peak_snapshot; verify_and_insert does not consult
+        /// them and always checks against the current chain tip
+        peak_snapshots: BTreeMap<u32, Vec<Word>>,
+    }
+
+    impl HeaderChain {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// The chain tip most recently set with set_chain_tip, if any
+        pub fn best_block(&self) -> Option<BestBlock> {
+            self.best_block
+        }
+
+        /// A previously-verified header, if present
+        pub fn verified_header(&self, block_num: u32) -> Option<&VerifiedHeader> {
+            self.headers.get(&block_num)
+        }
+
+        /// Record the node's current tip as the trust anchor future proofs are checked against
+        pub fn set_chain_tip(&mut self, block_num: u32, chain_commitment: Word) {
+            self.best_block = Some(BestBlock {
+                block_num,
+                chain_commitment,
+            });
+        }
+
+        /// Store a snapshot of the peaks at `forest` leaves; verification does not read it
+        pub fn record_peak_snapshot(&mut self, forest: u32, peaks: Vec<Word>) {
+            self.peak_snapshots.insert(forest, peaks);
+        }
+
+        /// Verify the MMR opening for `header` at `block_num` against the current chain tip, and
+        /// insert it only if it checks out. Genesis (block_num == 0) is trusted unconditionally.
+        pub fn verify_and_insert(
+            &mut self,
+            block_num: u32,
+            header: shared::BlockHeader,
+            commitment: Word,
+            proof: &shared::MmrProof,
+        ) -> Result<(), MidenRpcError> {
+            if block_num != 0 {
+                let best_block = self.best_block.ok_or(MidenRpcError::MissingField(
+                    "chain tip (call set_chain_tip first)",
+                ))?;
+
+                verify_mmr_opening(best_block, block_num, commitment, proof)?;
+            }
+
+            self.headers.insert(
+                block_num,
+                VerifiedHeader {
+                    block_num,
+                    commitment,
+                    header,
+                },
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Check that proof's peak set, for a forest of tip + 1 leaves, bags into best_block's
+    /// chain commitment, then verify leaf's authentication path against those peaks
+    fn verify_mmr_opening(
+        best_block: BestBlock,
+        leaf_index: u32,
+        leaf: Word,
+        proof: &shared::MmrProof,
+    ) -> Result<(), MidenRpcError> {
+        let forest = best_block.block_num as usize + 1;
+
+        let peaks: Vec<Word> = proof.peaks.iter().map(convert::digest_to_word).collect();
+        let mmr_peaks =
+            MmrPeaks::new(forest, peaks).map_err(|_| MidenRpcError::ProofVerification)?;
+
+        if mmr_peaks.hash_peaks() != best_block.chain_commitment {
+            return Err(MidenRpcError::ProofVerification);
+        }
+
+        let merkle_path: MerklePath = proof
+            .merkle_path
+            .iter()
+            .map(convert::digest_to_word)
+            .collect::<Vec<_>>()
+            .into();
+
+        let opening = NativeMmrProof {
+            forest,
+            position: leaf_index as usize,
+            merkle_path,
+        };
+
+        if !mmr_peaks.verify(leaf, opening) {
+            return Err(MidenRpcError::ProofVerification);
+        }
+
+        Ok(())
+    }
+}
+
+/// A high-level driver over sync_state that catches a client up to the node's current tip in
+/// a single call; sync_notes is not called
+pub mod sync {
+    use super::*;
+
+    /// The accumulated effect of catching up from some starting block to the chain tip
+    #[derive(Debug, Clone, Default)]
+    pub struct SyncSummary {
+        /// The last block number this summary is caught up to; persist this to resume later
+        pub block_num: u32,
+        pub account_updates: Vec<rpc_store::AccountUpdate>,
+        pub committed_notes: Vec<note::CommittedNote>,
+        pub nullifiers: Vec<Word>,
+    }
+
+    /// Drives sync_state for a fixed set of accounts and note tags, advancing block_num each
+    /// round until the client has caught up to the node's current tip
+    pub struct StateSyncer {
+        account_ids: Vec<AccountId>,
+        note_tags: Vec<NoteTag>,
+    }
+
+    impl StateSyncer {
+        pub fn new(account_ids: Vec<AccountId>, note_tags: Vec<NoteTag>) -> Self {
+            Self {
+                account_ids,
+                note_tags,
+            }
+        }
+
+        /// Sync from from_block up to the node's current tip, invoking on_progress with the
+        /// summary accumulated so far after each page that advances block_num. Returns once
+        /// caught up, or as soon as a page does not advance block_num; the returned block_num
+        /// is a checkpoint the caller can persist and resume from.
+        pub async fn sync_to_tip<T: RpcTransport>(
+            &self,
+            client: &mut MidenRpcClient<T>,
+            from_block: u32,
+            mut on_progress: impl FnMut(&SyncSummary),
+        ) -> Result<SyncSummary, MidenRpcError> {
+            let mut summary = SyncSummary {
+                block_num: from_block,
+                ..Default::default()
+            };
+
+            loop {
+                let status = client.get_status().await?;
+                if summary.block_num >= status.chain_tip {
+                    break;
+                }
+
+                let response = client
+                    .sync_state(summary.block_num, &self.account_ids, &self.note_tags)
+                    .await?;
+
+                summary.account_updates.extend(response.accounts);
+                summary.committed_notes.extend(response.notes);
+                summary
+                    .nullifiers
+                    .extend(response.nullifiers.iter().map(convert::digest_to_word));
+
+                if response.block_num <= summary.block_num {
+                    // The node didn't advance us; stop rather than spin on a stalled peer.
+                    break;
+                }
+                summary.block_num = response.block_num;
+
+                on_progress(&summary);
+            }
+
+            Ok(summary)
+        }
+    }
+}
+
+/// Per-method latency and outcome metrics, recorded through a pluggable MetricsRecorder
+/// Install one with MidenRpcClient::with_metrics; with none installed, recording is a no-op
+pub mod metrics {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    /// The result of an RPC call, as reported to a MetricsRecorder
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Outcome {
+        Success,
+        Error(tonic::Code),
+    }
+
+    /// Receives a timing/outcome report for every RPC call MidenRpcClient makes
+    pub trait MetricsRecorder: Send + Sync {
+        fn record(&self, method: &'static str, duration: Duration, outcome: Outcome);
+    }
+
+    /// Exponential bucket upper bounds, in milliseconds, from ~1ms to 30s.
+    const BUCKET_BOUNDS_MS: &[u64] = &[
+        1, 2, 4, 8, 16, 32, 64, 128, 256, 512, 1024, 2048, 4096, 8192, 16384, 30_000,
+    ];
+
+    #[derive(Debug, Clone)]
+    struct MethodHistogram {
+        /// One bucket per `BUCKET_BOUNDS_MS` entry, plus a final overflow bucket for >30s.
+        bucket_counts: Vec<u64>,
+        count: u64,
+        error_count: u64,
+    }
+
+    impl MethodHistogram {
+        fn new() -> Self {
+            Self {
+                bucket_counts: vec![0; BUCKET_BOUNDS_MS.len() + 1],
+                count: 0,
+                error_count: 0,
+            }
+        }
+
+        fn record(&mut self, duration: Duration, outcome: Outcome) {
+            let millis = duration.as_millis() as u64;
+            let bucket = BUCKET_BOUNDS_MS
+                .iter()
+                .position(|&bound| millis <= bound)
+                .unwrap_or(BUCKET_BOUNDS_MS.len());
+
+            self.bucket_counts[bucket] += 1;
+            self.count += 1;
+            if matches!(outcome, Outcome::Error(_)) {
+                self.error_count += 1;
+            }
+        }
+
+        fn percentile(&self, p: f64) -> Duration {
+            if self.count == 0 {
+                return Duration::ZERO;
+            }
+
+            let target = ((self.count as f64) * p).ceil() as u64;
+            let mut cumulative = 0;
+            for (i, &bucket_count) in self.bucket_counts.iter().enumerate() {
+                cumulative += bucket_count;
+                if cumulative >= target {
+                    let bound_ms = BUCKET_BOUNDS_MS.get(i).copied().unwrap_or(30_000);
+                    return Duration::from_millis(bound_ms);
+                }
+            }
+
+            Duration::from_millis(30_000)
+        }
+
+        fn snapshot(&self) -> MethodSnapshot {
+            MethodSnapshot {
+                count: self.count,
+                error_count: self.error_count,
+                p50: self.percentile(0.50),
+                p90: self.percentile(0.90),
+                p99: self.percentile(0.99),
+            }
+        }
+    }
+
+    /// Per-method call count and latency percentiles, as of the moment the snapshot was taken
+    #[derive(Debug, Clone, Copy)]
+    pub struct MethodSnapshot {
+        pub count: u64,
+        pub error_count: u64,
+        pub p50: Duration,
+        pub p90: Duration,
+        pub p99: Duration,
+    }
+
+    /// The default MetricsRecorder: an in-memory exponential-bucket histogram per method
+    #[derive(Debug, Default)]
+    pub struct HistogramRecorder {
+        histograms: Mutex<HashMap<&'static str, MethodHistogram>>,
+    }
+
+    impl HistogramRecorder {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// A snapshot of call counts and latency percentiles for every method recorded so far.
+        pub fn metrics_snapshot(&self) -> HashMap<&'static str, MethodSnapshot> {
+            self.histograms
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|(method, histogram)| (*method, histogram.snapshot()))
+                .collect()
+        }
+    }
+
+    impl MetricsRecorder for HistogramRecorder {
+        fn record(&self, method: &'static str, duration: Duration, outcome: Outcome) {
+            self.histograms
+                .lock()
+                .unwrap()
+                .entry(method)
+                .or_insert_with(MethodHistogram::new)
+                .record(duration, outcome);
+        }
+    }
+}
+
 // Conversion helpers
 mod convert {
     use super::*;
@@ -38,43 +837,137 @@ mod convert {
             id: account_id.to_bytes().to_vec(),
         }
     }
+
+    /// Convert proto Digest back to a native Word
+    pub fn digest_to_word(digest: &primitives::Digest) -> Word {
+        [
+            Felt::new(digest.d0),
+            Felt::new(digest.d1),
+            Felt::new(digest.d2),
+            Felt::new(digest.d3),
+        ]
+        .into()
+    }
+}
+
+/// Configuration for MidenRpcClient::submit_and_confirm_transaction
+#[derive(Debug, Clone)]
+pub struct ConfirmConfig {
+    /// Delay after the first unconfirmed poll; the first poll runs right after submission.
+    pub initial_backoff: Duration,
+    /// Upper bound the backoff is capped at between polls.
+    pub max_backoff: Duration,
+    /// Total time to wait for confirmation before giving up.
+    pub timeout: Duration,
+}
+
+impl Default for ConfirmConfig {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(8),
+            timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Adds up to 100ms of jitter to `backoff` to avoid synchronized retry storms.
+fn jittered(backoff: Duration) -> Duration {
+    let jitter_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .subsec_nanos() as u64
+        % 100;
+    backoff + Duration::from_millis(jitter_ms)
+}
+
+fn timeout_error() -> MidenRpcError {
+    MidenRpcError::Status {
+        code: tonic::Code::DeadlineExceeded,
+        message: "timed out waiting for transaction confirmation".to_string(),
+    }
 }
 
-pub struct MidenRpcClient {
-    client: ApiClient<Channel>,
+pub struct MidenRpcClient<T: RpcTransport = ApiClient<Channel>> {
+    client: T,
+    header_chain: header_chain::HeaderChain,
+    metrics: Option<Arc<dyn metrics::MetricsRecorder>>,
 }
 
-impl MidenRpcClient {
-    pub async fn connect(endpoint: impl Into<String>) -> Result<Self, String> {
+impl MidenRpcClient<ApiClient<Channel>> {
+    pub async fn connect(endpoint: impl Into<String>) -> Result<Self, MidenRpcError> {
         let endpoint_str = endpoint.into();
 
         let channel = Channel::from_shared(endpoint_str.clone())
-            .map_err(|e| format!("Invalid endpoint: {}", e))?
+            .map_err(MidenRpcError::Transport)?
             .tls_config(ClientTlsConfig::new().with_native_roots())
-            .map_err(|e| format!("TLS config error: {}", e))?
+            .map_err(MidenRpcError::Tls)?
             .connect()
             .await
-            .map_err(|e| format!("Failed to connect to {}: {}", endpoint_str, e))?;
+            .map_err(|source| MidenRpcError::Connect {
+                endpoint: endpoint_str,
+                source,
+            })?;
 
         let client = ApiClient::new(channel);
 
-        Ok(Self { client })
+        Ok(Self {
+            client,
+            header_chain: header_chain::HeaderChain::new(),
+            metrics: None,
+        })
+    }
+}
+
+impl<T: RpcTransport> MidenRpcClient<T> {
+    /// Build a client on top of an already-constructed transport, e.g. a mock in tests
+    pub fn new(client: T) -> Self {
+        Self {
+            client,
+            header_chain: header_chain::HeaderChain::new(),
+            metrics: None,
+        }
+    }
+
+    /// Install a MetricsRecorder that every RPC call reports its latency and outcome to
+    pub fn with_metrics(mut self, recorder: Arc<dyn metrics::MetricsRecorder>) -> Self {
+        self.metrics = Some(recorder);
+        self
+    }
+
+    /// Report an RPC call's latency and outcome to the installed MetricsRecorder, if any
+    fn record_outcome<R>(
+        &self,
+        method: &'static str,
+        start: Instant,
+        result: &Result<R, MidenRpcError>,
+    ) {
+        if let Some(recorder) = &self.metrics {
+            let outcome = match result {
+                Ok(_) => metrics::Outcome::Success,
+                Err(MidenRpcError::Status { code, .. }) => metrics::Outcome::Error(*code),
+                Err(_) => metrics::Outcome::Error(tonic::Code::Unknown),
+            };
+            recorder.record(method, start.elapsed(), outcome);
+        }
     }
 
-    /// Get the underlying tonic ApiClient for full access to all RPC methods:
-    pub fn client_mut(&mut self) -> &mut ApiClient<Channel> {
+    /// Get the underlying transport for full access to all RPC methods:
+    pub fn client_mut(&mut self) -> &mut T {
         &mut self.client
     }
 
     /// Get the status of the Miden node
-    pub async fn get_status(&mut self) -> Result<rpc::RpcStatus, String> {
-        let response = self
+    pub async fn get_status(&mut self) -> Result<rpc::RpcStatus, MidenRpcError> {
+        let start = Instant::now();
+        let result = self
             .client
             .status(Request::new(()))
             .await
-            .map_err(|e| format!("Status RPC failed: {}", e))?;
-
-        Ok(response.into_inner())
+            .map(|response| response.into_inner())
+            .map_err(MidenRpcError::from);
+        self.record_outcome("get_status", start, &result);
+        result
     }
 
     /// Get block header by number with optional MMR proof
@@ -82,37 +975,150 @@ impl MidenRpcClient {
         &mut self,
         block_num: Option<u32>,
         include_mmr_proof: bool,
-    ) -> Result<shared::BlockHeaderByNumberResponse, String> {
+    ) -> Result<shared::BlockHeaderByNumberResponse, MidenRpcError> {
         let request = shared::BlockHeaderByNumberRequest {
             block_num,
             include_mmr_proof: Some(include_mmr_proof),
         };
 
-        let response = self
+        let start = Instant::now();
+        let result = self
             .client
             .get_block_header_by_number(Request::new(request))
             .await
-            .map_err(|e| format!("GetBlockHeaderByNumber RPC failed: {}", e))?;
+            .map(|response| response.into_inner())
+            .map_err(MidenRpcError::from);
+        self.record_outcome("get_block_header", start, &result);
+        result
+    }
+
+    /// Fetch a block header and verify its MMR opening against the node's claimed chain tip.
+    /// Verified headers are cached and can later be retrieved via verified_header.
+    pub async fn get_verified_block_header(
+        &mut self,
+        block_num: u32,
+    ) -> Result<header_chain::VerifiedHeader, MidenRpcError> {
+        let status = self.get_status().await?;
+        self.header_chain
+            .set_chain_tip(status.chain_tip, convert::digest_to_word(&status.chain_commitment));
+
+        let response = self.get_block_header(Some(block_num), true).await?;
+        let header = response
+            .block_header
+            .ok_or(MidenRpcError::MissingField("block_header"))?;
+        let proof = response
+            .mmr_proof
+            .ok_or(MidenRpcError::MissingField("mmr_proof"))?;
+        let commitment = header
+            .commitment
+            .as_ref()
+            .map(convert::digest_to_word)
+            .ok_or(MidenRpcError::MissingField("block_header.commitment"))?;
+
+        self.header_chain
+            .verify_and_insert(block_num, header, commitment, &proof)?;
+
+        self.header_chain
+            .verified_header(block_num)
+            .cloned()
+            .ok_or(MidenRpcError::ProofVerification)
+    }
+
+    /// A previously-verified header from this client's local header chain
+    pub fn verified_header(&self, block_num: u32) -> Option<&header_chain::VerifiedHeader> {
+        self.header_chain.verified_header(block_num)
+    }
 
-        Ok(response.into_inner())
+    /// The chain tip this client last verified headers against
+    pub fn best_block(&self) -> Option<header_chain::BestBlock> {
+        self.header_chain.best_block()
     }
 
     /// Submit a proven transaction to the network
     pub async fn submit_transaction(
         &mut self,
         proven_tx_bytes: Vec<u8>,
-    ) -> Result<block_producer::SubmitProvenTransactionResponse, String> {
+    ) -> Result<block_producer::SubmitProvenTransactionResponse, MidenRpcError> {
         let request = transaction::ProvenTransaction {
             transaction: proven_tx_bytes,
         };
 
-        let response = self
+        let start = Instant::now();
+        let result = self
             .client
             .submit_proven_transaction(Request::new(request))
             .await
-            .map_err(|e| format!("SubmitProvenTransaction RPC failed: {}", e))?;
+            .map(|response| response.into_inner())
+            .map_err(MidenRpcError::from);
+        self.record_outcome("submit_transaction", start, &result);
+        result
+    }
+
+    /// Submit a proven transaction and poll (with backoff and jitter) until output_nullifiers
+    /// are consumed and affected_accounts' commitments have advanced, or config.timeout elapses
+    pub async fn submit_and_confirm_transaction(
+        &mut self,
+        proven_tx_bytes: Vec<u8>,
+        output_nullifiers: &[Word],
+        affected_accounts: &[AccountId],
+        config: ConfirmConfig,
+    ) -> Result<block_producer::SubmitProvenTransactionResponse, MidenRpcError> {
+        let mut pre_submission_commitments = Vec::with_capacity(affected_accounts.len());
+        for account_id in affected_accounts {
+            pre_submission_commitments.push(self.get_account_commitment(account_id).await?);
+        }
+
+        let response = self.submit_transaction(proven_tx_bytes).await?;
+
+        let deadline = Instant::now() + config.timeout;
+        let mut backoff = config.initial_backoff;
 
-        Ok(response.into_inner())
+        loop {
+            match self
+                .transaction_confirmed(output_nullifiers, affected_accounts, &pre_submission_commitments)
+                .await
+            {
+                Ok(true) => return Ok(response),
+                Ok(false) => {}
+                Err(_) if Instant::now() >= deadline => return Err(timeout_error()),
+                Err(err) => return Err(err),
+            }
+
+            if Instant::now() >= deadline {
+                return Err(timeout_error());
+            }
+
+            tokio::time::sleep(jittered(backoff)).await;
+            backoff = (backoff * 2).min(config.max_backoff);
+        }
+    }
+
+    /// Checks whether a submitted transaction's effects are visible yet
+    async fn transaction_confirmed(
+        &mut self,
+        output_nullifiers: &[Word],
+        affected_accounts: &[AccountId],
+        pre_submission_commitments: &[String],
+    ) -> Result<bool, MidenRpcError> {
+        if !output_nullifiers.is_empty() {
+            let response = self.check_nullifiers(output_nullifiers).await?;
+            if !response
+                .nullifiers
+                .iter()
+                .all(|update| update.block_num != 0)
+            {
+                return Ok(false);
+            }
+        }
+
+        for (account_id, pre_commitment) in affected_accounts.iter().zip(pre_submission_commitments)
+        {
+            if self.get_account_commitment(account_id).await? == *pre_commitment {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
     }
 
     /// Sync state for specified accounts and note tags
@@ -122,7 +1128,7 @@ impl MidenRpcClient {
         block_num: u32,
         account_ids: &[AccountId],
         note_tags: &[NoteTag],
-    ) -> Result<rpc_store::SyncStateResponse, String> {
+    ) -> Result<rpc_store::SyncStateResponse, MidenRpcError> {
         let account_ids = account_ids
             .iter()
             .map(|id| convert::account_id_to_proto(id))
@@ -136,13 +1142,15 @@ impl MidenRpcClient {
             note_tags,
         };
 
-        let response = self
+        let start = Instant::now();
+        let result = self
             .client
             .sync_state(Request::new(request))
             .await
-            .map_err(|e| format!("SyncState RPC failed: {}", e))?;
-
-        Ok(response.into_inner())
+            .map(|response| response.into_inner())
+            .map_err(MidenRpcError::from);
+        self.record_outcome("sync_state", start, &result);
+        result
     }
 
     /// Check nullifiers and get their proofs
@@ -150,20 +1158,22 @@ impl MidenRpcClient {
     pub async fn check_nullifiers(
         &mut self,
         nullifiers: &[Word],
-    ) -> Result<rpc_store::CheckNullifiersResponse, String> {
+    ) -> Result<rpc_store::CheckNullifiersResponse, MidenRpcError> {
         let nullifiers = nullifiers
             .iter()
             .map(|w| convert::word_to_digest(*w))
             .collect();
         let request = rpc_store::NullifierList { nullifiers };
 
-        let response = self
+        let start = Instant::now();
+        let result = self
             .client
             .check_nullifiers(Request::new(request))
             .await
-            .map_err(|e| format!("CheckNullifiers RPC failed: {}", e))?;
-
-        Ok(response.into_inner())
+            .map(|response| response.into_inner())
+            .map_err(MidenRpcError::from);
+        self.record_outcome("check_nullifiers", start, &result);
+        result
     }
 
     /// Get notes by their IDs
@@ -171,7 +1181,7 @@ impl MidenRpcClient {
     pub async fn get_notes_by_id(
         &mut self,
         note_ids: &[NoteId],
-    ) -> Result<note::CommittedNoteList, String> {
+    ) -> Result<note::CommittedNoteList, MidenRpcError> {
         let note_ids = note_ids
             .iter()
             .map(|id| note::NoteId {
@@ -180,41 +1190,61 @@ impl MidenRpcClient {
             .collect();
         let request = note::NoteIdList { ids: note_ids };
 
-        let response = self
+        let start = Instant::now();
+        let result = self
             .client
             .get_notes_by_id(Request::new(request))
             .await
-            .map_err(|e| format!("GetNotesById RPC failed: {}", e))?;
+            .map(|response| response.into_inner())
+            .map_err(MidenRpcError::from);
+        self.record_outcome("get_notes_by_id", start, &result);
+        result
+    }
 
-        Ok(response.into_inner())
+    /// Get notes by their IDs, decoded into native `miden_objects::note::Note`s.
+    pub async fn get_notes_by_id_typed(
+        &mut self,
+        note_ids: &[NoteId],
+    ) -> Result<Vec<Note>, MidenRpcError> {
+        let response = self.get_notes_by_id(note_ids).await?;
+
+        response
+            .notes
+            .into_iter()
+            .map(|note| {
+                Note::read_from_bytes(&note.details)
+                    .map_err(|e| MidenRpcError::Decode(e.to_string()))
+            })
+            .collect()
     }
 
     /// Fetch account commitment from the Miden network
     pub async fn get_account_commitment(
         &mut self,
         account_id: &AccountId,
-    ) -> Result<String, String> {
+    ) -> Result<String, MidenRpcError> {
         let account_id_bytes = account_id.to_bytes();
 
         let request = Request::new(account::AccountId {
             id: account_id_bytes.to_vec(),
         });
 
-        let response = self
+        let start = Instant::now();
+        let result = self
             .client
             .get_account_details(request)
             .await
-            .map_err(|e| format!("RPC call failed: {}", e))?;
-
-        let account_details = response.into_inner();
+            .map_err(MidenRpcError::from);
+        self.record_outcome("get_account_commitment", start, &result);
+        let account_details = result?.into_inner();
 
         let summary = account_details
             .summary
-            .ok_or_else(|| "No account summary in response".to_string())?;
+            .ok_or(MidenRpcError::MissingField("summary"))?;
 
         let commitment = summary
             .account_commitment
-            .ok_or_else(|| "No commitment in account summary".to_string())?;
+            .ok_or(MidenRpcError::MissingField("account_commitment"))?;
 
         // Convert Digest to hex string
         let bytes = [
@@ -231,20 +1261,32 @@ impl MidenRpcClient {
     pub async fn get_account_details(
         &mut self,
         account_id: &AccountId,
-    ) -> Result<account::AccountDetails, String> {
+    ) -> Result<account::AccountDetails, MidenRpcError> {
         let account_id_bytes = account_id.to_bytes();
 
         let request = Request::new(account::AccountId {
             id: account_id_bytes.to_vec(),
         });
 
-        let response = self
+        let start = Instant::now();
+        let result = self
             .client
             .get_account_details(request)
             .await
-            .map_err(|e| format!("RPC call failed: {}", e))?;
+            .map(|response| response.into_inner())
+            .map_err(MidenRpcError::from);
+        self.record_outcome("get_account_details", start, &result);
+        result
+    }
+
+    /// Fetch full account details decoded into a native `miden_objects::account::Account`.
+    pub async fn get_account_typed(
+        &mut self,
+        account_id: &AccountId,
+    ) -> Result<Account, MidenRpcError> {
+        let details = self.get_account_details(account_id).await?;
 
-        Ok(response.into_inner())
+        Account::read_from_bytes(&details.details).map_err(|e| MidenRpcError::Decode(e.to_string()))
     }
 
     /// Get account proofs for the specified accounts
@@ -255,7 +1297,7 @@ impl MidenRpcClient {
         account_requests: Vec<rpc_store::account_proofs_request::AccountRequest>,
         include_headers: bool,
         code_commitments: &[Word],
-    ) -> Result<rpc_store::AccountProofs, String> {
+    ) -> Result<rpc_store::AccountProofs, MidenRpcError> {
         let code_commitments = code_commitments
             .iter()
             .map(|w| convert::word_to_digest(*w))
@@ -267,29 +1309,43 @@ impl MidenRpcClient {
             code_commitments,
         };
 
-        let response = self
+        let start = Instant::now();
+        let result = self
             .client
             .get_account_proofs(Request::new(request))
             .await
-            .map_err(|e| format!("GetAccountProofs RPC failed: {}", e))?;
-
-        Ok(response.into_inner())
+            .map(|response| response.into_inner())
+            .map_err(MidenRpcError::from);
+        self.record_outcome("get_account_proofs", start, &result);
+        result
     }
 
     /// Get raw block data by block number
     pub async fn get_block_by_number(
         &mut self,
         block_num: u32,
-    ) -> Result<blockchain::MaybeBlock, String> {
+    ) -> Result<blockchain::MaybeBlock, MidenRpcError> {
         let request = blockchain::BlockNumber { block_num };
 
-        let response = self
+        let start = Instant::now();
+        let result = self
             .client
             .get_block_by_number(Request::new(request))
             .await
-            .map_err(|e| format!("GetBlockByNumber RPC failed: {}", e))?;
+            .map(|response| response.into_inner())
+            .map_err(MidenRpcError::from);
+        self.record_outcome("get_block_by_number", start, &result);
+        result
+    }
 
-        Ok(response.into_inner())
+    /// Get raw block data by block number, decoded into a native `miden_objects::block::Block`.
+    pub async fn get_block_typed(&mut self, block_num: u32) -> Result<Option<Block>, MidenRpcError> {
+        let response = self.get_block_by_number(block_num).await?;
+
+        response
+            .block
+            .map(|bytes| Block::read_from_bytes(&bytes).map_err(|e| MidenRpcError::Decode(e.to_string())))
+            .transpose()
     }
 
     /// Submit a proven batch of transactions to the network
@@ -297,18 +1353,20 @@ impl MidenRpcClient {
     pub async fn submit_proven_batch(
         &mut self,
         encoded_batch: Vec<u8>,
-    ) -> Result<block_producer::SubmitProvenBatchResponse, String> {
+    ) -> Result<block_producer::SubmitProvenBatchResponse, MidenRpcError> {
         let request = transaction::ProvenTransactionBatch {
             encoded: encoded_batch,
         };
 
-        let response = self
+        let start = Instant::now();
+        let result = self
             .client
             .submit_proven_batch(Request::new(request))
             .await
-            .map_err(|e| format!("SubmitProvenBatch RPC failed: {}", e))?;
-
-        Ok(response.into_inner())
+            .map(|response| response.into_inner())
+            .map_err(MidenRpcError::from);
+        self.record_outcome("submit_proven_batch", start, &result);
+        result
     }
 
     /// Check nullifiers by prefixes (only 16-bit prefixes are supported)
@@ -318,20 +1376,22 @@ impl MidenRpcClient {
         prefix_len: u32,
         nullifiers: Vec<u32>,
         block_num: u32,
-    ) -> Result<rpc_store::CheckNullifiersByPrefixResponse, String> {
+    ) -> Result<rpc_store::CheckNullifiersByPrefixResponse, MidenRpcError> {
         let request = rpc_store::CheckNullifiersByPrefixRequest {
             prefix_len,
             nullifiers,
             block_num,
         };
 
-        let response = self
+        let start = Instant::now();
+        let result = self
             .client
             .check_nullifiers_by_prefix(Request::new(request))
             .await
-            .map_err(|e| format!("CheckNullifiersByPrefix RPC failed: {}", e))?;
-
-        Ok(response.into_inner())
+            .map(|response| response.into_inner())
+            .map_err(MidenRpcError::from);
+        self.record_outcome("check_nullifiers_by_prefix", start, &result);
+        result
     }
 
     /// Sync account vault updates within a block range
@@ -341,20 +1401,22 @@ impl MidenRpcClient {
         account_id: &AccountId,
         block_from: u32,
         block_to: Option<u32>,
-    ) -> Result<rpc_store::SyncAccountVaultResponse, String> {
+    ) -> Result<rpc_store::SyncAccountVaultResponse, MidenRpcError> {
         let request = rpc_store::SyncAccountVaultRequest {
             account_id: Some(convert::account_id_to_proto(account_id)),
             block_from,
             block_to,
         };
 
-        let response = self
+        let start = Instant::now();
+        let result = self
             .client
             .sync_account_vault(Request::new(request))
             .await
-            .map_err(|e| format!("SyncAccountVault RPC failed: {}", e))?;
-
-        Ok(response.into_inner())
+            .map(|response| response.into_inner())
+            .map_err(MidenRpcError::from);
+        self.record_outcome("sync_account_vault", start, &result);
+        result
     }
 
     /// Sync notes by note tags and block height
@@ -363,7 +1425,7 @@ impl MidenRpcClient {
         &mut self,
         block_num: u32,
         note_tags: &[NoteTag],
-    ) -> Result<rpc_store::SyncNotesResponse, String> {
+    ) -> Result<rpc_store::SyncNotesResponse, MidenRpcError> {
         let note_tags = note_tags.iter().map(|tag| tag.as_u32()).collect();
 
         let request = rpc_store::SyncNotesRequest {
@@ -371,13 +1433,15 @@ impl MidenRpcClient {
             note_tags,
         };
 
-        let response = self
+        let start = Instant::now();
+        let result = self
             .client
             .sync_notes(Request::new(request))
             .await
-            .map_err(|e| format!("SyncNotes RPC failed: {}", e))?;
-
-        Ok(response.into_inner())
+            .map(|response| response.into_inner())
+            .map_err(MidenRpcError::from);
+        self.record_outcome("sync_notes", start, &result);
+        result
     }
 
     /// Sync storage map updates for specified account within a block range
@@ -387,19 +1451,263 @@ impl MidenRpcClient {
         account_id: &AccountId,
         block_from: u32,
         block_to: Option<u32>,
-    ) -> Result<rpc_store::SyncStorageMapsResponse, String> {
+    ) -> Result<rpc_store::SyncStorageMapsResponse, MidenRpcError> {
         let request = rpc_store::SyncStorageMapsRequest {
             account_id: Some(convert::account_id_to_proto(account_id)),
             block_from,
             block_to,
         };
 
-        let response = self
+        let start = Instant::now();
+        let result = self
             .client
             .sync_storage_maps(Request::new(request))
             .await
-            .map_err(|e| format!("SyncStorageMaps RPC failed: {}", e))?;
+            .map(|response| response.into_inner())
+            .map_err(MidenRpcError::from);
+        self.record_outcome("sync_storage_maps", start, &result);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mock::MockTransport;
+
+    fn word(n: u64) -> Word {
+        [Felt::new(n), Felt::new(0), Felt::new(0), Felt::new(0)].into()
+    }
+
+    fn confirm_config() -> ConfirmConfig {
+        ConfirmConfig {
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(2),
+            timeout: Duration::from_millis(50),
+        }
+    }
+
+    #[tokio::test]
+    async fn submit_and_confirm_returns_once_nullifier_consumed() {
+        let nullifier = word(1);
+        let transport = MockTransport::new()
+            .push_submit_transaction_response(Default::default())
+            .push_check_nullifiers_response(rpc_store::CheckNullifiersResponse {
+                nullifiers: vec![rpc_store::NullifierUpdate {
+                    nullifier: Some(convert::word_to_digest(nullifier)),
+                    block_num: 0,
+                }],
+                ..Default::default()
+            })
+            .push_check_nullifiers_response(rpc_store::CheckNullifiersResponse {
+                nullifiers: vec![rpc_store::NullifierUpdate {
+                    nullifier: Some(convert::word_to_digest(nullifier)),
+                    block_num: 5,
+                }],
+                ..Default::default()
+            });
+
+        let mut client = MidenRpcClient::new(transport);
+        let result = client
+            .submit_and_confirm_transaction(vec![], &[nullifier], &[], confirm_config())
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn submit_and_confirm_times_out_when_never_confirmed() {
+        let nullifier = word(1);
+        let mut transport = MockTransport::new().push_submit_transaction_response(Default::default());
+        // enough unconfirmed responses to outlast the 50ms timeout at a 1-2ms backoff
+        for _ in 0..100 {
+            transport = transport.push_check_nullifiers_response(rpc_store::CheckNullifiersResponse {
+                nullifiers: vec![rpc_store::NullifierUpdate {
+                    nullifier: Some(convert::word_to_digest(nullifier)),
+                    block_num: 0,
+                }],
+                ..Default::default()
+            });
+        }
+
+        let mut client = MidenRpcClient::new(transport);
+        let result = client
+            .submit_and_confirm_transaction(vec![], &[nullifier], &[], confirm_config())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(MidenRpcError::Status {
+                code: tonic::Code::DeadlineExceeded,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn sync_to_tip_pages_to_chain_tip_via_sync_state() {
+        let chain_commitment = convert::word_to_digest(word(0));
+        let transport = MockTransport::new()
+            .push_status_response(rpc::RpcStatus {
+                chain_tip: 2,
+                chain_commitment: chain_commitment.clone(),
+                ..Default::default()
+            })
+            .push_status_response(rpc::RpcStatus {
+                chain_tip: 2,
+                chain_commitment,
+                ..Default::default()
+            })
+            .push_sync_state_response(rpc_store::SyncStateResponse {
+                block_num: 2,
+                ..Default::default()
+            });
+
+        let mut client = MidenRpcClient::new(transport);
+        let syncer = sync::StateSyncer::new(vec![], vec![]);
+
+        let summary = syncer
+            .sync_to_tip(&mut client, 0, |_| {})
+            .await
+            .expect("sync should succeed");
+
+        assert_eq!(summary.block_num, 2);
+    }
+
+    fn build_mmr_proof(leaves: &[Word], leaf_index: usize) -> (Word, shared::MmrProof) {
+        use miden_objects::crypto::merkle::Mmr;
+
+        let mut mmr = Mmr::default();
+        for leaf in leaves {
+            mmr.add(*leaf);
+        }
+
+        let forest = leaves.len();
+        let peaks = mmr.peaks(forest).expect("forest within range");
+        let opening = mmr.open(leaf_index).expect("leaf_index within range");
+
+        let proof = shared::MmrProof {
+            peaks: peaks
+                .peaks()
+                .iter()
+                .map(|w| convert::word_to_digest(*w))
+                .collect(),
+            merkle_path: opening
+                .merkle_path
+                .nodes()
+                .iter()
+                .map(|w| convert::word_to_digest(*w))
+                .collect(),
+        };
+
+        (peaks.hash_peaks(), proof)
+    }
+
+    #[test]
+    fn verify_and_insert_accepts_genuine_mmr_opening() {
+        let leaves = vec![word(10), word(20), word(30)];
+        let leaf_index = 1;
+        let (chain_commitment, proof) = build_mmr_proof(&leaves, leaf_index);
+
+        let mut chain = header_chain::HeaderChain::new();
+        chain.set_chain_tip(leaves.len() as u32 - 1, chain_commitment);
+
+        let result = chain.verify_and_insert(
+            leaf_index as u32,
+            shared::BlockHeader::default(),
+            leaves[leaf_index],
+            &proof,
+        );
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn verify_and_insert_rejects_tampered_leaf() {
+        let leaves = vec![word(10), word(20), word(30)];
+        let leaf_index = 1;
+        let (chain_commitment, proof) = build_mmr_proof(&leaves, leaf_index);
+
+        let mut chain = header_chain::HeaderChain::new();
+        chain.set_chain_tip(leaves.len() as u32 - 1, chain_commitment);
+
+        // Claim a different leaf commitment than the one the opening was built for.
+        let result = chain.verify_and_insert(
+            leaf_index as u32,
+            shared::BlockHeader::default(),
+            word(999),
+            &proof,
+        );
+
+        assert!(matches!(result, Err(MidenRpcError::ProofVerification)));
+    }
+
+    #[test]
+    fn verify_and_insert_rejects_tampered_peak() {
+        let leaves = vec![word(10), word(20), word(30)];
+        let leaf_index = 1;
+        let (chain_commitment, mut proof) = build_mmr_proof(&leaves, leaf_index);
+        proof.peaks[0] = convert::word_to_digest(word(999));
+
+        let mut chain = header_chain::HeaderChain::new();
+        chain.set_chain_tip(leaves.len() as u32 - 1, chain_commitment);
+
+        let result = chain.verify_and_insert(
+            leaf_index as u32,
+            shared::BlockHeader::default(),
+            leaves[leaf_index],
+            &proof,
+        );
+
+        assert!(matches!(result, Err(MidenRpcError::ProofVerification)));
+    }
+
+    #[tokio::test]
+    async fn get_account_typed_round_trips_a_decodable_account() {
+        use miden_objects::testing::account::AccountBuilder;
+
+        let account = AccountBuilder::new([0u8; 32])
+            .build_existing()
+            .expect("builder produces a valid existing account");
+        let account_id = account.id();
+
+        let transport = MockTransport::new().with_account_details(
+            account_id,
+            account::AccountDetails {
+                details: account.to_bytes(),
+                ..Default::default()
+            },
+        );
+
+        let mut client = MidenRpcClient::new(transport);
+        let decoded = client
+            .get_account_typed(&account_id)
+            .await
+            .expect("well-formed account bytes should decode");
+
+        assert_eq!(decoded.id(), account_id);
+    }
+
+    #[tokio::test]
+    async fn get_account_typed_surfaces_decode_errors() {
+        use miden_objects::testing::account::AccountBuilder;
+
+        let account = AccountBuilder::new([1u8; 32])
+            .build_existing()
+            .expect("builder produces a valid existing account");
+        let account_id = account.id();
+
+        let transport = MockTransport::new().with_account_details(
+            account_id,
+            account::AccountDetails {
+                details: vec![0xFF; 4],
+                ..Default::default()
+            },
+        );
+
+        let mut client = MidenRpcClient::new(transport);
+        let result = client.get_account_typed(&account_id).await;
 
-        Ok(response.into_inner())
+        assert!(matches!(result, Err(MidenRpcError::Decode(_))));
     }
 }